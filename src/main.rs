@@ -84,7 +84,7 @@ async fn main() -> Result<()> {
             commands::import::execute(&chain)?;
         }
         Commands::Ui => {
-            ui::run()?;
+            ui::run().await?;
         }
     }
 
@@ -2,155 +2,117 @@
 //!
 //! Provides an interactive terminal interface for wallet management.
 
+mod screen;
+mod screens;
+mod theme;
+
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    cursor::Show,
+    event::{DisableMouseCapture, EnableMouseCapture, Event, EventStream, KeyEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::{
-    backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
-    text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
-    Terminal,
-};
+use futures::{FutureExt, StreamExt};
+use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
+use std::time::Duration;
+use tokio::time::interval;
 
-/// App state for the TUI
-struct App {
-    selected_chain: usize,
-    chains: Vec<String>,
-    should_quit: bool,
-}
+use screen::{OutgoingMessage, Screen, ScreenName};
+use screens::{chain_detail::ChainDetailScreen, chain_list::ChainListScreen};
 
-impl App {
-    fn new() -> Self {
-        Self {
-            selected_chain: 0,
-            chains: vec![
-                "Cardano (ADA)".to_string(),
-                "Bitcoin (BTC) - Coming Soon".to_string(),
-                "Solana (SOL) - Coming Soon".to_string(),
-            ],
-            should_quit: false,
-        }
-    }
+/// How often to redraw even without an input event (e.g. for live balance refresh)
+const TICK_RATE: Duration = Duration::from_millis(250);
 
-    fn next_chain(&mut self) {
-        self.selected_chain = (self.selected_chain + 1) % self.chains.len();
-    }
+/// Terminal type used throughout the TUI
+pub type DefaultTerminal = Terminal<CrosstermBackend<io::Stdout>>;
 
-    fn previous_chain(&mut self) {
-        if self.selected_chain > 0 {
-            self.selected_chain -= 1;
-        } else {
-            self.selected_chain = self.chains.len() - 1;
+/// Build the concrete screen a `ScreenName` refers to
+fn build_screen(name: ScreenName) -> Box<dyn Screen> {
+    match name {
+        ScreenName::ChainDetail { chain_name, theme } => {
+            Box::new(ChainDetailScreen::new(chain_name, theme))
         }
     }
 }
 
-/// Run the TUI application
-pub fn run() -> Result<()> {
-    // Setup terminal
+/// Set up the terminal for the TUI and install a panic hook that restores it first
+///
+/// If `run_app` panics, the default hook would otherwise fire with the terminal still
+/// in raw mode / the alternate screen, corrupting the user's shell. Chaining through
+/// `restore()` first keeps the backtrace readable.
+fn init() -> Result<DefaultTerminal> {
+    let original_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        let _ = restore();
+        original_hook(panic_info);
+    }));
+
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-
-    // Create app state
-    let mut app = App::new();
+    let terminal = Terminal::new(backend)?;
 
-    // Main loop
-    let result = run_app(&mut terminal, &mut app);
+    Ok(terminal)
+}
 
-    // Restore terminal
+/// Restore the terminal to its original state
+fn restore() -> Result<()> {
     disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, Show)?;
+
+    Ok(())
+}
+
+/// Run the TUI application
+pub async fn run() -> Result<()> {
+    let mut terminal = init()?;
+
+    // The navigation stack always starts on the chain list; the main loop
+    // renders and dispatches keys to whichever screen is on top.
+    let mut stack: Vec<Box<dyn Screen>> = vec![Box::new(ChainListScreen::new())];
+
+    let result = run_app(&mut terminal, &mut stack).await;
+
+    restore()?;
 
     result
 }
 
-fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App) -> Result<()> {
+async fn run_app(terminal: &mut DefaultTerminal, stack: &mut Vec<Box<dyn Screen>>) -> Result<()> {
+    let mut events = EventStream::new();
+    let mut tick = interval(TICK_RATE);
+
     loop {
-        terminal.draw(|f| {
-            let chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .margin(2)
-                .constraints([
-                    Constraint::Length(3),
-                    Constraint::Min(10),
-                    Constraint::Length(3),
-                ])
-                .split(f.area());
-
-            // Header
-            let header = Paragraph::new(Line::from(vec![
-                Span::styled("begin", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                Span::raw(" - Multi-Chain Wallet"),
-            ]))
-            .block(Block::default().borders(Borders::ALL));
-            f.render_widget(header, chunks[0]);
-
-            // Chain list
-            let items: Vec<ListItem> = app
-                .chains
-                .iter()
-                .enumerate()
-                .map(|(i, chain)| {
-                    let style = if i == app.selected_chain {
-                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
-                    } else {
-                        Style::default()
-                    };
-                    let prefix = if i == app.selected_chain { "► " } else { "  " };
-                    ListItem::new(format!("{}{}", prefix, chain)).style(style)
-                })
-                .collect();
-
-            let list = List::new(items)
-                .block(Block::default().borders(Borders::ALL).title("Chains"));
-            f.render_widget(list, chunks[1]);
-
-            // Footer
-            let footer = Paragraph::new("↑↓ Navigate | Enter: Select | q: Quit")
-                .style(Style::default().fg(Color::DarkGray))
-                .block(Block::default().borders(Borders::ALL));
-            f.render_widget(footer, chunks[2]);
-        })?;
-
-        // Handle input
-        if event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
-                    match key.code {
-                        KeyCode::Char('q') => {
-                            app.should_quit = true;
+        let top = stack.last().expect("navigation stack is never empty");
+        terminal.draw(|f| top.render(f))?;
+
+        // Race the next input event against the redraw tick so a key press is handled
+        // the instant it arrives instead of waiting out the poll interval.
+        let event = events.next().fuse();
+        tokio::select! {
+            maybe_event = event => {
+                let Some(event) = maybe_event else { return Ok(()) };
+                if let Event::Key(key) = event? {
+                    if key.kind == KeyEventKind::Press {
+                        let top = stack.last_mut().expect("navigation stack is never empty");
+                        match top.handle_key(key) {
+                            Some(OutgoingMessage::Push(name)) => stack.push(build_screen(name)),
+                            Some(OutgoingMessage::Pop) => {
+                                stack.pop();
+                                if stack.is_empty() {
+                                    return Ok(());
+                                }
+                            }
+                            Some(OutgoingMessage::Quit) => return Ok(()),
+                            None => {}
                         }
-                        KeyCode::Up | KeyCode::Char('k') => {
-                            app.previous_chain();
-                        }
-                        KeyCode::Down | KeyCode::Char('j') => {
-                            app.next_chain();
-                        }
-                        KeyCode::Enter => {
-                            // TODO: Show chain details/actions
-                        }
-                        _ => {}
                     }
                 }
             }
-        }
-
-        if app.should_quit {
-            return Ok(());
+            _ = tick.tick() => {}
         }
     }
 }
@@ -0,0 +1,35 @@
+//! Screen subsystem - each view on the navigation stack is a `Screen`
+//!
+//! The main loop only ever renders and dispatches keys to the top of the
+//! stack; a screen asks to navigate by returning an `OutgoingMessage`.
+
+use crossterm::event::KeyEvent;
+use ratatui::Frame;
+
+use super::theme::Theme;
+
+/// Identifies a screen to push onto the navigation stack, along with
+/// whatever context the destination screen needs to render itself.
+pub enum ScreenName {
+    /// Per-chain actions (Portfolio, Receive/Deposit, Send)
+    ChainDetail { chain_name: String, theme: Theme },
+}
+
+/// A message a screen sends back to the main loop after handling a key
+pub enum OutgoingMessage {
+    /// Push a new screen on top of the stack
+    Push(ScreenName),
+    /// Pop the current screen, returning to the one below it
+    Pop,
+    /// Exit the application entirely
+    Quit,
+}
+
+/// A single view in the navigation stack
+pub trait Screen {
+    /// Draw this screen into the given frame
+    fn render(&self, frame: &mut Frame);
+
+    /// Handle a key press, optionally asking the main loop to navigate
+    fn handle_key(&mut self, key: KeyEvent) -> Option<OutgoingMessage>;
+}
@@ -0,0 +1,115 @@
+//! Chain detail screen - per-chain actions (Portfolio, Receive, Send)
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::ui::screen::{OutgoingMessage, Screen};
+use crate::ui::theme::Theme;
+
+/// Actions available for a selected chain
+const ACTIONS: &[&str] = &["Portfolio", "Receive / Deposit", "Send"];
+
+/// Per-chain action screen, pushed when a chain is selected from the chain list
+pub struct ChainDetailScreen {
+    chain_name: String,
+    selected_action: usize,
+    theme: Theme,
+}
+
+impl ChainDetailScreen {
+    pub fn new(chain_name: String, theme: Theme) -> Self {
+        Self {
+            chain_name,
+            selected_action: 0,
+            theme,
+        }
+    }
+
+    fn next_action(&mut self) {
+        self.selected_action = (self.selected_action + 1) % ACTIONS.len();
+    }
+
+    fn previous_action(&mut self) {
+        if self.selected_action > 0 {
+            self.selected_action -= 1;
+        } else {
+            self.selected_action = ACTIONS.len() - 1;
+        }
+    }
+}
+
+impl Screen for ChainDetailScreen {
+    fn render(&self, frame: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(10),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Header
+        let header = Paragraph::new(Line::from(vec![
+            Span::styled(self.chain_name.clone(), self.theme.header),
+        ]))
+        .block(Block::default().borders(Borders::ALL).border_style(self.theme.border));
+        frame.render_widget(header, chunks[0]);
+
+        // Action list
+        let items: Vec<ListItem> = ACTIONS
+            .iter()
+            .enumerate()
+            .map(|(i, action)| {
+                let style = if i == self.selected_action {
+                    self.theme.selected_item
+                } else {
+                    self.theme.normal_item
+                };
+                let prefix = if i == self.selected_action { "► " } else { "  " };
+                ListItem::new(format!("{}{}", prefix, action)).style(style)
+            })
+            .collect();
+
+        let list = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(self.theme.border)
+                .title("Actions"),
+        );
+        frame.render_widget(list, chunks[1]);
+
+        // Footer
+        let footer = Paragraph::new("↑↓ Navigate | Enter: Select | Esc: Back | q: Quit")
+            .style(self.theme.footer)
+            .block(Block::default().borders(Borders::ALL).border_style(self.theme.border));
+        frame.render_widget(footer, chunks[2]);
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Option<OutgoingMessage> {
+        match key.code {
+            KeyCode::Char('q') => Some(OutgoingMessage::Quit),
+            KeyCode::Esc => Some(OutgoingMessage::Pop),
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.previous_action();
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.next_action();
+                None
+            }
+            KeyCode::Enter => {
+                // TODO: wire each action up to its own screen (balance query, address
+                // display, send flow) once those exist.
+                None
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,380 @@
+//! Chain list screen - the app's home screen
+//!
+//! Lets the user pick a chain and theme; `Enter` pushes that chain's detail screen.
+//! Pressing `/` starts an incremental fuzzy search over the chain names.
+
+use std::collections::HashSet;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use nucleo_matcher::pattern::{CaseMatching, Normalization, Pattern};
+use nucleo_matcher::{Config, Matcher, Utf32Str};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::ui::screen::{OutgoingMessage, Screen, ScreenName};
+use crate::ui::theme::Theme;
+
+/// A chain entry surviving the current fuzzy filter
+struct FilteredChain {
+    /// Index into `ChainListScreen::chains`
+    chain_index: usize,
+    /// Character indices the query matched, for highlighting
+    match_indices: Vec<u32>,
+}
+
+/// Home screen listing the supported chains
+pub struct ChainListScreen {
+    selected_chain: usize,
+    chains: Vec<String>,
+    theme: Theme,
+    themes: Vec<Theme>,
+    selected_theme: usize,
+    searching: bool,
+    query: String,
+    filtered: Vec<FilteredChain>,
+}
+
+impl ChainListScreen {
+    pub fn new() -> Self {
+        let themes = Theme::presets();
+        let chains = vec![
+            "Cardano (ADA)".to_string(),
+            "Bitcoin (BTC) - Coming Soon".to_string(),
+            "Solana (SOL) - Coming Soon".to_string(),
+        ];
+        let filtered = unfiltered(&chains);
+
+        Self {
+            selected_chain: 0,
+            chains,
+            theme: themes[0].clone(),
+            themes,
+            selected_theme: 0,
+            searching: false,
+            query: String::new(),
+            filtered,
+        }
+    }
+
+    fn next_chain(&mut self) {
+        if !self.filtered.is_empty() {
+            self.selected_chain = (self.selected_chain + 1) % self.filtered.len();
+        }
+    }
+
+    fn previous_chain(&mut self) {
+        if self.filtered.is_empty() {
+            return;
+        }
+        if self.selected_chain > 0 {
+            self.selected_chain -= 1;
+        } else {
+            self.selected_chain = self.filtered.len() - 1;
+        }
+    }
+
+    /// Cycle to the next theme preset
+    fn next_theme(&mut self) {
+        self.selected_theme = (self.selected_theme + 1) % self.themes.len();
+        self.theme = self.themes[self.selected_theme].clone();
+    }
+
+    /// Re-run the fuzzy filter against the current query and reset the selection
+    fn refilter(&mut self) {
+        self.filtered = if self.query.is_empty() {
+            unfiltered(&self.chains)
+        } else {
+            fuzzy_filter(&self.chains, &self.query)
+        };
+        self.selected_chain = 0;
+    }
+
+    fn enter_search(&mut self) {
+        self.searching = true;
+        self.query.clear();
+        self.refilter();
+    }
+
+    fn exit_search(&mut self) {
+        self.searching = false;
+        self.query.clear();
+        self.refilter();
+    }
+
+    /// Push the detail screen for whichever chain is currently selected
+    fn push_selected(&self) -> Option<OutgoingMessage> {
+        self.filtered.get(self.selected_chain).map(|entry| {
+            OutgoingMessage::Push(ScreenName::ChainDetail {
+                chain_name: self.chains[entry.chain_index].clone(),
+                theme: self.theme.clone(),
+            })
+        })
+    }
+}
+
+/// The identity filter: every chain, in its original order, with no highlights
+fn unfiltered(chains: &[String]) -> Vec<FilteredChain> {
+    (0..chains.len())
+        .map(|chain_index| FilteredChain {
+            chain_index,
+            match_indices: Vec::new(),
+        })
+        .collect()
+}
+
+/// Score every chain name against `query`, keep every chain the pattern matches, and
+/// sort them from best to worst match. An empty query matches every chain with
+/// score 0, so all chains are kept in their original order.
+fn fuzzy_filter(chains: &[String], query: &str) -> Vec<FilteredChain> {
+    let mut matcher = Matcher::new(Config::DEFAULT);
+    let pattern = Pattern::parse(query, CaseMatching::Ignore, Normalization::Smart);
+
+    let mut scored: Vec<(FilteredChain, u32)> = Vec::new();
+    let mut buf = Vec::new();
+    for (chain_index, chain) in chains.iter().enumerate() {
+        let haystack = Utf32Str::new(chain, &mut buf);
+        let mut match_indices = Vec::new();
+        if let Some(score) = pattern.indices(haystack, &mut matcher, &mut match_indices) {
+            scored.push((
+                FilteredChain {
+                    chain_index,
+                    match_indices,
+                },
+                score,
+            ));
+        }
+    }
+
+    scored.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+    scored.into_iter().map(|(filtered, _)| filtered).collect()
+}
+
+/// Split a chain name into spans, highlighting the characters a fuzzy match hit
+fn highlighted_spans(name: &str, match_indices: &[u32], theme: &Theme, selected: bool) -> Line<'static> {
+    let indices: HashSet<u32> = match_indices.iter().copied().collect();
+    let base_style = if selected {
+        theme.selected_item
+    } else {
+        theme.normal_item
+    };
+
+    let spans = name
+        .chars()
+        .enumerate()
+        .map(|(i, ch)| {
+            let style = if indices.contains(&(i as u32)) {
+                theme.match_highlight
+            } else {
+                base_style
+            };
+            Span::styled(ch.to_string(), style)
+        })
+        .collect::<Vec<_>>();
+
+    Line::from(spans)
+}
+
+impl Screen for ChainListScreen {
+    fn render(&self, frame: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(2)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(10),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Header
+        let header = Paragraph::new(Line::from(vec![
+            Span::styled("begin", self.theme.header),
+            Span::raw(" - Multi-Chain Wallet"),
+        ]))
+        .block(Block::default().borders(Borders::ALL).border_style(self.theme.border));
+        frame.render_widget(header, chunks[0]);
+
+        // Chain list
+        let items: Vec<ListItem> = self
+            .filtered
+            .iter()
+            .enumerate()
+            .map(|(row, entry)| {
+                let selected = row == self.selected_chain;
+                let prefix = if selected { "► " } else { "  " };
+                let mut spans = vec![Span::raw(prefix)];
+                spans.extend(
+                    highlighted_spans(&self.chains[entry.chain_index], &entry.match_indices, &self.theme, selected)
+                        .spans,
+                );
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let title = if self.searching {
+            format!("Chains — /{}", self.query)
+        } else {
+            "Chains".to_string()
+        };
+
+        let list = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(self.theme.border)
+                .title(title),
+        );
+        frame.render_widget(list, chunks[1]);
+
+        // Footer
+        let hint = if self.searching {
+            "Type to filter | Enter: Select | Esc: Clear search".to_string()
+        } else {
+            format!(
+                "↑↓ Navigate | /: Search | Enter: Select | t: Theme ({}) | q: Quit",
+                self.theme.name
+            )
+        };
+        let footer = Paragraph::new(hint)
+            .style(self.theme.footer)
+            .block(Block::default().borders(Borders::ALL).border_style(self.theme.border));
+        frame.render_widget(footer, chunks[2]);
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Option<OutgoingMessage> {
+        if self.searching {
+            return match key.code {
+                KeyCode::Esc => {
+                    self.exit_search();
+                    None
+                }
+                KeyCode::Up => {
+                    self.previous_chain();
+                    None
+                }
+                KeyCode::Down => {
+                    self.next_chain();
+                    None
+                }
+                KeyCode::Backspace => {
+                    self.query.pop();
+                    self.refilter();
+                    None
+                }
+                KeyCode::Char(c) => {
+                    self.query.push(c);
+                    self.refilter();
+                    None
+                }
+                KeyCode::Enter => self.push_selected(),
+                _ => None,
+            };
+        }
+
+        match key.code {
+            KeyCode::Char('q') => Some(OutgoingMessage::Quit),
+            KeyCode::Char('/') => {
+                self.enter_search();
+                None
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.previous_chain();
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.next_chain();
+                None
+            }
+            KeyCode::Char('t') => {
+                self.next_theme();
+                None
+            }
+            KeyCode::Enter => self.push_selected(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chains() -> Vec<String> {
+        vec![
+            "Cardano (ADA)".to_string(),
+            "Bitcoin (BTC) - Coming Soon".to_string(),
+            "Solana (SOL) - Coming Soon".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_fuzzy_filter_matches_and_excludes() {
+        let chains = chains();
+        let results = fuzzy_filter(&chains, "ada");
+
+        // "ada" has no subsequence match in "Bitcoin ..." or "Solana ..." (no 'd'),
+        // so only Cardano should survive the filter.
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].chain_index, 0);
+    }
+
+    #[test]
+    fn test_fuzzy_filter_no_match_returns_empty() {
+        let chains = chains();
+        let results = fuzzy_filter(&chains, "zzz");
+
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_filter_empty_query_matches_all_in_original_order() {
+        let chains = chains();
+        let results = fuzzy_filter(&chains, "");
+
+        assert_eq!(
+            results.iter().map(|f| f.chain_index).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_filter_orders_best_match_first() {
+        let chains = vec![
+            "abc".to_string(),
+            "a....................b....................c".to_string(),
+        ];
+        let results = fuzzy_filter(&chains, "abc");
+
+        // A contiguous match should outrank one scattered across large gaps.
+        assert_eq!(results[0].chain_index, 0);
+    }
+
+    #[test]
+    fn test_highlighted_spans_marks_only_matched_indices() {
+        let theme = Theme::dark();
+        let line = highlighted_spans("Cardano", &[0, 1, 2], &theme, false);
+
+        assert_eq!(line.spans.len(), "Cardano".chars().count());
+        for (i, span) in line.spans.iter().enumerate() {
+            let expected = if i < 3 {
+                theme.match_highlight
+            } else {
+                theme.normal_item
+            };
+            assert_eq!(span.style, expected);
+        }
+    }
+
+    #[test]
+    fn test_highlighted_spans_uses_selected_style_for_unmatched_chars() {
+        let theme = Theme::dark();
+        let line = highlighted_spans("Cardano", &[], &theme, true);
+
+        for span in &line.spans {
+            assert_eq!(span.style, theme.selected_item);
+        }
+    }
+}
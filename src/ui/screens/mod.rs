@@ -0,0 +1,4 @@
+//! Concrete `Screen` implementations
+
+pub mod chain_detail;
+pub mod chain_list;
@@ -0,0 +1,58 @@
+//! Color themes for the TUI
+//!
+//! Styles are grouped into named slots so the draw code never hard-codes a
+//! `Color` value; swapping the active `Theme` re-skins every screen at once.
+
+use ratatui::style::{Color, Modifier, Style};
+
+/// A named palette of styles used throughout the TUI
+#[derive(Debug, Clone)]
+pub struct Theme {
+    /// Display name shown in the footer hint
+    pub name: &'static str,
+    /// Style for the header/title bar
+    pub header: Style,
+    /// Style for the currently selected list item
+    pub selected_item: Style,
+    /// Style for unselected list items
+    pub normal_item: Style,
+    /// Style for footer hint text
+    pub footer: Style,
+    /// Style for block borders
+    pub border: Style,
+    /// Style for the characters a fuzzy search query matched
+    pub match_highlight: Style,
+}
+
+impl Theme {
+    /// Default dark theme, tuned for a black terminal background
+    pub fn dark() -> Self {
+        Self {
+            name: "dark",
+            header: Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            selected_item: Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            normal_item: Style::default(),
+            footer: Style::default().fg(Color::DarkGray),
+            border: Style::default(),
+            match_highlight: Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Light theme for terminals with a light background
+    pub fn light() -> Self {
+        Self {
+            name: "light",
+            header: Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD),
+            selected_item: Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+            normal_item: Style::default().fg(Color::Black),
+            footer: Style::default().fg(Color::Gray),
+            border: Style::default().fg(Color::Black),
+            match_highlight: Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// All built-in presets, in cycle order
+    pub fn presets() -> Vec<Theme> {
+        vec![Theme::dark(), Theme::light()]
+    }
+}